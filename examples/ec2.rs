@@ -3,19 +3,16 @@ extern crate bitflags;
 extern crate chrono;
 extern crate curl;
 extern crate env_logger;
-extern crate regex;
 extern crate warheadhateus;
 extern crate xml;
 
 use chrono::UTC;
 use curl::http;
-use regex::Regex;
-use std::env;
 use std::error::Error;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader, Write};
-use warheadhateus::{AWSAuth, AWSAuthError, hashed_data, HttpRequestMethod, Region, Service};
+use std::io::{self, Write};
+use warheadhateus::{AWSAuth, AWSAuthError, Credentials, CredentialsError, CredentialsFile,
+                    EnvConfig, hashed_data, HttpRequestMethod, Region, Service};
 use xml::reader::{EventReader, XmlEvent};
 
 const EX_STDOUT: &'static str = "Unable to write to stdout!";
@@ -68,45 +65,29 @@ impl fmt::Display for Response {
     }
 }
 
-fn credentials() -> Result<(String, String), io::Error> {
-    let mut ak = String::new();
-    let mut sk = String::new();
-
-    if let Some(hd) = env::home_dir() {
-        let akre = Regex::new(r"^aws_access_key_id = (.*)").expect("Failed to compile regex!");
-        let skre = Regex::new(r"^aws_secret_access_key = (.*)").expect("Failed to compile regex!");
-        let creds = try!(File::open(hd.join(".aws").join("credentials")));
-        let f = BufReader::new(creds);
-
-        for line in f.lines() {
-            if let Ok(l) = line {
-                if let Some(caps) = akre.captures(&l) {
-                    ak.push_str(caps.at(1).expect("Unable to capture!"));
-                }
-
-                if let Some(scaps) = skre.captures(&l) {
-                    sk.push_str(scaps.at(1).expect("Unable to capture!"));
-                }
-            }
+fn credentials(profile: Option<&str>) -> Result<Credentials, CredentialsError> {
+    let config = try!(EnvConfig::load());
+    match config.credentials {
+        Some(credentials) => Ok(credentials),
+        None => {
+            let file = try!(CredentialsFile::load(&config));
+            file.credentials(config.profile_name(profile))
         }
     }
-
-    Ok((ak, sk))
 }
 
 fn run() -> Result<(), AWSAuthError> {
-    match credentials() {
-        Ok((ak, sk)) => {
+    match credentials(None) {
+        Ok(credentials) => {
             let mut auth = try!(AWSAuth::new(URL_1));
             let payload_hash = try!(hashed_data(None));
             let date = UTC::now();
             let fmtdate = &date.format(DATE_TIME_FMT).to_string();
             auth.set_request_type(HttpRequestMethod::GET);
             auth.set_payload_hash(&payload_hash);
-            auth.set_date(UTC::now());
+            auth.set_date(date);
             auth.set_service(Service::EC2);
-            auth.set_access_key_id(&ak);
-            auth.set_secret_access_key(&sk);
+            credentials.apply(&mut auth);
             auth.set_region(Region::UsEast1);
             auth.add_header("Host", HOST);
             auth.add_header("X-Amz-Date", &fmtdate);
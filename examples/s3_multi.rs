@@ -86,7 +86,7 @@ fn run() -> Result<(), AWSAuthError> {
     let ah = try!(auth.auth_header());
     let mut oc: OutCache = Default::default();
     oc.hl("Authorization", ah);
-    let cl = try!(auth.content_length(payload.len()));
+    let cl = try!(auth.content_length(payload.len() as u64));
     oc.hl("Content-Length", cl);
     let ss = try!(auth.seed_signature());
     oc.hl("Seed Signature", &ss);
@@ -99,7 +99,7 @@ fn run() -> Result<(), AWSAuthError> {
     let mut tl = 0;
     let mut count = 1;
 
-    for (i, chunk) in payload.chunks(chunk_size).enumerate() {
+    for (i, chunk) in payload.chunks(chunk_size as usize).enumerate() {
         let cs = try!(auth.chunk_signature(&ps, &chunk));
         oc.hl(&format!("Chunk {} Signature", i + 1), &cs);
         let cb = try!(auth.chunk_body(&cs, &chunk));
@@ -126,7 +126,7 @@ fn run() -> Result<(), AWSAuthError> {
         oc.hl(&format!("Chunk {} Prefix", count), String::from_utf8_lossy(&cb[..np]));
     }
     oc.hl("Total Payload Length", tl);
-    oc.hl("CL = TPL", cl == tl);
+    oc.hl("CL = TPL", cl == tl as u64);
 
     oc.flush();
     Ok(())
@@ -1,68 +1,43 @@
 extern crate chrono;
 extern crate clap;
 extern crate env_logger;
-extern crate regex;
 extern crate warheadhateus;
 
 use chrono::UTC;
 use clap::{Arg, App};
-use regex::Regex;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader, Write};
-use warheadhateus::{AWSAuth, AWSAuthError, HttpRequestMethod, SigningVersion};
+use std::io::{self, Write};
+use warheadhateus::{AWSAuth, AWSAuthError, Credentials, CredentialsError, CredentialsFile,
+                    EnvConfig, HttpRequestMethod, SigningVersion};
 
-const DATE_TIME_FMT: &'static str = "%Y%m%dT%H%M%SZ";
 const EX_STDOUT: &'static str = "Unable to write to stdout!";
 
-fn credentials() -> Result<(String, String), io::Error> {
-    let mut ak = String::new();
-    let mut sk = String::new();
-
-    if let Some(hd) = env::home_dir() {
-        let akre = Regex::new(r"^aws_access_key_id = (.*)").expect("Failed to compile regex!");
-        let skre = Regex::new(r"^aws_secret_access_key = (.*)").expect("Failed to compile regex!");
-        let creds = try!(File::open(hd.join(".aws").join("credentials")));
-        let f = BufReader::new(creds);
-
-        for line in f.lines() {
-            if let Ok(l) = line {
-                if let Some(caps) = akre.captures(&l) {
-                    ak.push_str(caps.at(1).expect("Unable to capture!"));
-                }
-
-                if let Some(scaps) = skre.captures(&l) {
-                    sk.push_str(scaps.at(1).expect("Unable to capture!"));
-                }
-            }
+fn credentials(profile: Option<&str>) -> Result<Credentials, CredentialsError> {
+    let config = try!(EnvConfig::load());
+    match config.credentials {
+        Some(credentials) => Ok(credentials),
+        None => {
+            let file = try!(CredentialsFile::load(&config));
+            file.credentials(config.profile_name(profile))
         }
     }
-
-    Ok((ak, sk))
 }
 
-fn run(token: &str) -> Result<(), AWSAuthError> {
-    let date = UTC::now();
-    let fmtdate = date.format(DATE_TIME_FMT).to_string();
-    if let Ok((access_key, secret_key)) = credentials() {
-        let mut url = format!("https://sts.amazonaws.com\
+fn run(token: &str, profile: Option<&str>) -> Result<(), AWSAuthError> {
+    if let Ok(credentials) = credentials(profile) {
+        // AWSAccessKeyId, SignatureVersion, SignatureMethod and Timestamp are added when signing.
+        let url = format!("https://sts.amazonaws.com\
                           ?Action=GetSessionToken\
                           &Version=2011-06-15\
                           &DurationSeconds=3600\
                           &SerialNumber=arn:aws:iam::184438746295:mfa/jozias\
-                          &TokenCode={}\
-                          &AWSAccessKeyId={}\
-                          &SignatureVersion=2\
-                          &SignatureMethod=HmacSHA256\
-                          &Timestamp={}", token, access_key, fmtdate);
+                          &TokenCode={}", token);
         let mut auth = try!(AWSAuth::new(&url));
         auth.set_version(SigningVersion::Two);
         auth.set_request_type(HttpRequestMethod::GET);
-        auth.set_access_key_id(&access_key);
-        auth.set_secret_access_key(&secret_key);
+        auth.set_date(UTC::now());
+        credentials.apply(&mut auth);
 
-        let sig = try!(auth.signature());
-        url.push_str(&format!("&Signature={}", sig));
+        let url = try!(auth.presigned_url());
         writeln!(io::stdout(), "\x1b[32;1m{}\x1b[0m{}", "URL: ", url).expect(EX_STDOUT);
         Ok(())
     } else {
@@ -82,7 +57,13 @@ fn main() {
                                .value_name("TOKEN")
                                .help("Sets a token code from an authenticator")
                                .takes_value(true))
+                          .arg(Arg::with_name("profile")
+                               .short("p")
+                               .long("profile")
+                               .value_name("PROFILE")
+                               .help("Sets the credentials profile (default: AWS_PROFILE)")
+                               .takes_value(true))
                           .get_matches();
     let token = matches.value_of("token").unwrap_or("123456");
-    run(token).expect("Failed to chunk request!");
+    run(token, matches.value_of("profile")).expect("Failed to chunk request!");
 }
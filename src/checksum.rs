@@ -0,0 +1,68 @@
+//! Incremental flexible checksums (for `x-amz-checksum-*` trailers).
+use crc32c;
+use crc32fast;
+use rustc_serialize::base64::{STANDARD, ToBase64};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use types::Checksum;
+
+enum State {
+    CRC32(crc32fast::Hasher),
+    CRC32C(u32),
+    SHA1(Sha1),
+    SHA256(Sha256),
+}
+
+/// Calculates a flexible checksum over data given in one or more parts.
+pub struct ChecksumHasher {
+    state: State,
+}
+
+impl ChecksumHasher {
+    /// Create a new hasher for the given checksum algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use warheadhateus::{Checksum, ChecksumHasher};
+    ///
+    /// let mut hasher = ChecksumHasher::new(Checksum::CRC32C);
+    /// hasher.update(b"Hello, ");
+    /// hasher.update(b"World!");
+    /// assert!(hasher.finish() == "TVUQaA==");
+    /// ```
+    pub fn new(checksum: Checksum) -> ChecksumHasher {
+        let state = match checksum {
+            Checksum::CRC32 => State::CRC32(crc32fast::Hasher::new()),
+            Checksum::CRC32C => State::CRC32C(0),
+            Checksum::SHA1 => State::SHA1(Sha1::new()),
+            Checksum::SHA256 => State::SHA256(Sha256::new()),
+        };
+        ChecksumHasher { state: state }
+    }
+
+    /// Add the given data to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        match self.state {
+            State::CRC32(ref mut h) => h.update(data),
+            State::CRC32C(ref mut crc) => *crc = crc32c::crc32c_append(*crc, data),
+            State::SHA1(ref mut h) => h.update(data),
+            State::SHA256(ref mut h) => h.update(data),
+        }
+    }
+
+    /// Finish the checksum, returning it base64 encoded.
+    pub fn finish(self) -> String {
+        let bytes = match self.state {
+            State::CRC32(h) => be_bytes(h.finalize()),
+            State::CRC32C(crc) => be_bytes(crc),
+            State::SHA1(h) => h.finalize().to_vec(),
+            State::SHA256(h) => h.finalize().to_vec(),
+        };
+        bytes.to_base64(STANDARD)
+    }
+}
+
+fn be_bytes(crc: u32) -> Vec<u8> {
+    vec![(crc >> 24) as u8, (crc >> 16) as u8, (crc >> 8) as u8, crc as u8]
+}
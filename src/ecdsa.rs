@@ -0,0 +1,72 @@
+//! AWS Signature Version 4A (ECDSA P-256) key derivation and signing.
+use error::AWSAuthError;
+use p256::ecdsa::{Signature, SigningKey};
+use p256::ecdsa::signature::{Signer, Verifier};
+use rustc_serialize::hex::{FromHex, ToHex};
+use utils;
+
+/// The label used in the key derivation fixed input.
+const LABEL: &'static str = "AWS4-ECDSA-P256-SHA256";
+/// The order of the P-256 curve minus 2 (big-endian).
+const N_MINUS_2: [u8; 32] = [0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff,
+                             0xff, 0xff, 0xff, 0xff, 0xff, 0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17,
+                             0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x4f];
+/// The maximum length of a hex encoded DER signature.
+pub const MAX_SIGNATURE_LEN: usize = 144;
+
+/// Derive the ECDSA P-256 signing key for the given credentials.
+///
+/// This is the NIST SP 800-108 counter mode KDF (HMAC-SHA256) described in the SigV4A
+/// specification.  The counter is incremented until the candidate key is less than N - 1.
+pub fn signing_key(access_key_id: &str, secret_access_key: &str)
+                   -> Result<SigningKey, AWSAuthError> {
+    let mut input_key = String::from("AWS4A");
+    input_key.push_str(secret_access_key);
+
+    for counter in 1..255 {
+        let mut fixed_input = Vec::with_capacity(32 + access_key_id.len());
+        fixed_input.extend_from_slice(&[0, 0, 0, 1]);
+        fixed_input.extend_from_slice(LABEL.as_bytes());
+        fixed_input.push(0);
+        fixed_input.extend_from_slice(access_key_id.as_bytes());
+        fixed_input.push(counter);
+        fixed_input.extend_from_slice(&[0, 0, 1, 0]);
+
+        let mut k0 = try!(utils::signed_data(&fixed_input, input_key.as_bytes()));
+
+        if k0[..] <= N_MINUS_2[..] {
+            // The private key is k0 + 1.
+            for byte in k0.iter_mut().rev() {
+                let (sum, carry) = byte.overflowing_add(1);
+                *byte = sum;
+                if !carry {
+                    break;
+                }
+            }
+            return SigningKey::from_slice(&k0)
+                .map_err(|_| AWSAuthError::Other("Unable to create the ECDSA signing key"));
+        }
+    }
+
+    Err(AWSAuthError::Other("Unable to derive the ECDSA signing key"))
+}
+
+/// Sign the given data (ECDSA P-256 over the SHA-256 digest) and hex encode the DER signature.
+pub fn sign(key: &SigningKey, data: &[u8]) -> Result<String, AWSAuthError> {
+    let signature: Signature = try!(key.try_sign(data)
+        .map_err(|_| AWSAuthError::Other("Unable to generate the ECDSA signature")));
+    Ok(signature.to_der().as_bytes().to_hex())
+}
+
+/// Verify the given hex encoded DER signature over the given data.
+pub fn verify(key: &SigningKey, data: &[u8], signature: &str) -> bool {
+    let der = match signature.from_hex() {
+        Ok(der) => der,
+        Err(_) => return false,
+    };
+
+    match Signature::from_der(&der) {
+        Ok(sig) => key.verifying_key().verify(data, &sig).is_ok(),
+        Err(_) => false,
+    }
+}
@@ -1,7 +1,9 @@
 use chrono;
+use post_policy::Condition;
 use sodium_sys;
 use std::error::Error;
 use std::fmt;
+use std::io;
 use std::string::FromUtf8Error;
 
 #[derive(Debug)]
@@ -9,6 +11,8 @@ use std::string::FromUtf8Error;
 pub enum AWSAuthError {
     /// Error thrown converting from UTF-8.
     FromUtf8Error(FromUtf8Error),
+    /// Error thrown when the url given to `AWSAuth::new` is malformed.
+    InvalidUrl(&'static str),
     /// Error thrown when running methods not valid for the current mode.
     ModeError,
     /// Error thrown during sodium operations.
@@ -23,6 +27,7 @@ impl Error for AWSAuthError {
     fn description(&self) -> &str {
         match *self {
             AWSAuthError::FromUtf8Error(ref e) => e.description(),
+            AWSAuthError::InvalidUrl(e) => e,
             AWSAuthError::ModeError => "ModeError",
             AWSAuthError::Nacl(_) => "Sodium SSError",
             AWSAuthError::Other(e) => e,
@@ -35,6 +40,7 @@ impl fmt::Display for AWSAuthError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let display = match *self {
             AWSAuthError::FromUtf8Error(_) => "FromUtf8Error",
+            AWSAuthError::InvalidUrl(e) => e,
             AWSAuthError::ModeError => "ModeError",
             AWSAuthError::Nacl(_) => "SSError",
             AWSAuthError::Other(e) => e,
@@ -62,6 +68,72 @@ impl From<chrono::format::ParseError> for AWSAuthError {
     }
 }
 
+#[derive(Debug)]
+/// Credential and Configuration Loading Error Types
+pub enum CredentialsError {
+    /// Only part of an access key pair is set.  The value is the name of the missing setting.
+    IncompleteKeyPair(&'static str),
+    /// A profile's settings are inconsistent.  The values are the profile name and the reason.
+    InvalidProfile(String, &'static str),
+    /// The configured region is not a known `Region`.
+    InvalidRegion(String),
+    /// Error thrown reading a credentials file.
+    Io(io::Error),
+    /// The home directory (and so the default credentials file) is not known.
+    NoHomeDirectory,
+    /// A credentials file is malformed.  The values are the (1-based) line number and the reason.
+    Parse(usize, &'static str),
+    /// The `source_profile`s of a role profile form a cycle.  The value is the chain of profile
+    /// names, ending with the repeated one.
+    ProfileCycle(Vec<String>),
+    /// The named profile is not in the credentials (or config) file.
+    ProfileNotFound(String),
+}
+
+impl Error for CredentialsError {
+    fn description(&self) -> &str {
+        match *self {
+            CredentialsError::IncompleteKeyPair(_) => "Only part of the access key pair is set",
+            CredentialsError::InvalidProfile(..) => "The profile settings are inconsistent",
+            CredentialsError::InvalidRegion(_) => "The region is not known",
+            CredentialsError::Io(ref e) => e.description(),
+            CredentialsError::NoHomeDirectory => "The home directory is not known",
+            CredentialsError::Parse(..) => "The credentials file is malformed",
+            CredentialsError::ProfileCycle(_) => "The source profiles form a cycle",
+            CredentialsError::ProfileNotFound(_) => "The profile was not found",
+        }
+    }
+}
+
+impl fmt::Display for CredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CredentialsError::IncompleteKeyPair(name) => {
+                write!(f, "IncompleteKeyPair: {} is not set", name)
+            }
+            CredentialsError::InvalidProfile(ref p, reason) => {
+                write!(f, "InvalidProfile: {}: {}", p, reason)
+            }
+            CredentialsError::InvalidRegion(ref r) => write!(f, "InvalidRegion: {}", r),
+            CredentialsError::Io(ref e) => write!(f, "{}", e),
+            CredentialsError::NoHomeDirectory => write!(f, "NoHomeDirectory"),
+            CredentialsError::Parse(line, reason) => {
+                write!(f, "Parse: line {}: {}", line, reason)
+            }
+            CredentialsError::ProfileCycle(ref chain) => {
+                write!(f, "ProfileCycle: {}", chain.join(" -> "))
+            }
+            CredentialsError::ProfileNotFound(ref p) => write!(f, "ProfileNotFound: {}", p),
+        }
+    }
+}
+
+impl From<io::Error> for CredentialsError {
+    fn from(e: io::Error) -> CredentialsError {
+        CredentialsError::Io(e)
+    }
+}
+
 #[derive(Debug,PartialEq)]
 /// Thrown when a given region cannot be parsed.
 pub struct ParseRegionError;
@@ -93,3 +165,116 @@ impl fmt::Display for ParseServiceError {
         write!(f, "ParseServiceError")
     }
 }
+
+#[derive(Debug)]
+/// Request Verification Error Types
+pub enum VerifyError {
+    /// Error thrown while regenerating the signature.
+    Auth(AWSAuthError),
+    /// The checksum trailer of an `aws-chunked` body does not match the decoded payload.
+    ChecksumMismatch,
+    /// The POST upload does not satisfy the given condition of its policy.
+    ConditionFailed(Condition),
+    /// The signature of an `aws-chunked` chunk (or trailer) does not match the chunk data.
+    ChunkSignatureMismatch,
+    /// The credential scope does not match the date, region or service of the verifier.
+    CredentialScopeMismatch,
+    /// The decoded `aws-chunked` payload does not match `x-amz-decoded-content-length`.
+    DecodedLengthMismatch,
+    /// The presigned request (or POST policy) has expired.
+    Expired,
+    /// A field of the POST upload form is not covered by any condition of its policy.
+    FieldNotInPolicy(String),
+    /// The `host` header is not included in the signed headers.
+    HostNotSigned,
+    /// The `X-Amz-Expires` value is not a number between 1 and 604800.
+    InvalidExpires,
+    /// Error thrown reading the request body.
+    Io(io::Error),
+    /// The `Authorization` header, or the presigned query parameters, could not be parsed.
+    MalformedAuthorization,
+    /// An `aws-chunked` chunk could not be parsed, or the body ended before the final chunk.
+    MalformedChunk,
+    /// The credential could not be parsed.
+    MalformedCredential,
+    /// The request date could not be parsed.
+    MalformedDate,
+    /// The POST policy could not be decoded or parsed.
+    MalformedPolicy,
+    /// The request has no `Authorization` header and no presigned query parameters.
+    MissingAuthentication,
+    /// The request has no `X-Amz-Date` or `Date`.
+    MissingDate,
+    /// A field required to verify a POST upload is missing from the form.
+    MissingField(String),
+    /// A header listed in the signed headers is not present on the request.
+    MissingSignedHeader(String),
+    /// The request date is outside of the allowed clock skew.
+    RequestTimeTooSkewed,
+    /// The calculated signature does not match the signature given with the request.
+    SignatureMismatch,
+    /// The access key id is not known to the secret lookup.
+    UnknownAccessKey(String),
+    /// The signing algorithm is not supported.
+    UnsupportedAlgorithm(String),
+}
+
+impl Error for VerifyError {
+    fn description(&self) -> &str {
+        match *self {
+            VerifyError::Auth(ref e) => e.description(),
+            VerifyError::ChecksumMismatch => "The checksum does not match",
+            VerifyError::ConditionFailed(_) => "A POST policy condition is not satisfied",
+            VerifyError::ChunkSignatureMismatch => "The chunk signature does not match",
+            VerifyError::CredentialScopeMismatch => "The credential scope does not match",
+            VerifyError::DecodedLengthMismatch => "The decoded content length does not match",
+            VerifyError::Expired => "The presigned request has expired",
+            VerifyError::FieldNotInPolicy(_) => "A form field is not covered by the POST policy",
+            VerifyError::HostNotSigned => "The host header is not signed",
+            VerifyError::InvalidExpires => "X-Amz-Expires must be between 1 and 604800",
+            VerifyError::Io(ref e) => e.description(),
+            VerifyError::MalformedAuthorization => "The authorization could not be parsed",
+            VerifyError::MalformedChunk => "The chunk could not be parsed",
+            VerifyError::MalformedCredential => "The credential could not be parsed",
+            VerifyError::MalformedDate => "The request date could not be parsed",
+            VerifyError::MalformedPolicy => "The POST policy could not be parsed",
+            VerifyError::MissingAuthentication => "The request is not signed",
+            VerifyError::MissingDate => "The request date is missing",
+            VerifyError::MissingField(_) => "A required form field is missing",
+            VerifyError::MissingSignedHeader(_) => "A signed header is missing",
+            VerifyError::RequestTimeTooSkewed => "The request time is too skewed",
+            VerifyError::SignatureMismatch => "The signature does not match",
+            VerifyError::UnknownAccessKey(_) => "The access key id is not known",
+            VerifyError::UnsupportedAlgorithm(_) => "The signing algorithm is not supported",
+        }
+    }
+}
+
+#[cfg_attr(feature = "clippy", allow(use_debug))]
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VerifyError::Auth(ref e) => write!(f, "{}", e),
+            VerifyError::ConditionFailed(ref c) => write!(f, "ConditionFailed: {:?}", c),
+            VerifyError::FieldNotInPolicy(ref n) => write!(f, "FieldNotInPolicy: {}", n),
+            VerifyError::Io(ref e) => write!(f, "{}", e),
+            VerifyError::MissingField(ref n) => write!(f, "MissingField: {}", n),
+            VerifyError::MissingSignedHeader(ref h) => write!(f, "MissingSignedHeader: {}", h),
+            VerifyError::UnknownAccessKey(ref k) => write!(f, "UnknownAccessKey: {}", k),
+            VerifyError::UnsupportedAlgorithm(ref a) => write!(f, "UnsupportedAlgorithm: {}", a),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl From<AWSAuthError> for VerifyError {
+    fn from(e: AWSAuthError) -> VerifyError {
+        VerifyError::Auth(e)
+    }
+}
+
+impl From<io::Error> for VerifyError {
+    fn from(e: io::Error) -> VerifyError {
+        VerifyError::Io(e)
+    }
+}
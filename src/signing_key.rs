@@ -0,0 +1,186 @@
+//! Derived AWS Signature Version 4 signing keys.
+use {AWS4_REQUEST, AWSAuthResult, DATE_FMT};
+use chrono::{DateTime, UTC};
+use error::AWSAuthError;
+use rustc_serialize::hex::ToHex;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use types::{Region, Service};
+use utils;
+
+/// A v4 signing key derived for a (secret access key, date, region, service) scope.
+///
+/// Deriving the key takes four HMAC operations, so reusing it (see `AWSAuth::set_signing_key`)
+/// saves the derivation on every signature made for the same scope.  The key keeps a SHA256 hash
+/// of the secret access key it was derived from (not the secret itself), so it is never used to
+/// sign for other credentials.
+#[derive(Clone)]
+pub struct SigningKey {
+    date: String,
+    key: Vec<u8>,
+    region: Region,
+    secret_hash: String,
+    service: Service,
+}
+
+impl SigningKey {
+    /// Derive the signing key for the given scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate chrono;
+    /// # extern crate warheadhateus;
+    /// #
+    /// # fn main() {
+    /// use chrono::UTC;
+    /// use warheadhateus::{Region, Service, SigningKey};
+    ///
+    /// let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+    /// match SigningKey::new(secret, &UTC::now(), Region::UsEast1, Service::S3) {
+    ///     Ok(key) => {
+    ///         assert!(key.matches(secret, &UTC::now(), Region::UsEast1, Service::S3));
+    ///     }
+    ///     Err(_) => {
+    ///         // Failure
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn new(secret_access_key: &str,
+               date: &DateTime<UTC>,
+               region: Region,
+               service: Service)
+               -> Result<SigningKey, AWSAuthError> {
+        let mut key = String::from("AWS4");
+        key.push_str(secret_access_key);
+
+        let date = date.format(DATE_FMT).to_string();
+        let aws4 = AWS4_REQUEST.as_bytes();
+        let date_key = try!(utils::signed_data(date.as_bytes(), key.as_bytes()));
+        let date_region_key = try!(utils::signed_data(region.to_string().as_bytes(), &date_key));
+        let date_region_service_key = try!(utils::signed_data(service.to_string().as_bytes(),
+                                                              &date_region_key));
+        let signing_key = try!(utils::signed_data(aws4, &date_region_service_key));
+
+        Ok(SigningKey {
+            date: date,
+            key: signing_key,
+            region: region,
+            secret_hash: try!(secret_hash(secret_access_key)),
+            service: service,
+        })
+    }
+
+    /// Check if the key was derived from the given secret access key, for the given date (day),
+    /// region and service.
+    pub fn matches(&self,
+                   secret_access_key: &str,
+                   date: &DateTime<UTC>,
+                   region: Region,
+                   service: Service)
+                   -> bool {
+        match secret_hash(secret_access_key) {
+            Ok(hash) => self.matches_hash(&hash, date, region, service),
+            Err(_) => false,
+        }
+    }
+
+    /// Check the scope of the key, given the hash of the secret access key.
+    pub(crate) fn matches_hash(&self,
+                               secret_hash: &str,
+                               date: &DateTime<UTC>,
+                               region: Region,
+                               service: Service)
+                               -> bool {
+        utils::constant_time_eq(self.secret_hash.as_bytes(), secret_hash.as_bytes()) &&
+        self.region == region && self.service == service &&
+        self.date == date.format(DATE_FMT).to_string()
+    }
+
+    /// Sign the given string to sign, returning the hex encoded signature.
+    pub fn sign(&self, string_to_sign: &str) -> AWSAuthResult {
+        let signature = try!(utils::signed_data(string_to_sign.as_bytes(), &self.key));
+        Ok(signature.to_hex())
+    }
+}
+
+/// A thread-safe cache of signing keys.
+///
+/// One key is kept per (secret access key, region, service), where the secret access key is held
+/// as its SHA256 hash.  When a key is requested for a different date than the cached key (i.e.
+/// after the UTC date boundary), it is derived again and replaces the cached key.
+#[derive(Default)]
+pub struct SigningKeyCache {
+    keys: RwLock<HashMap<(String, Region, Service), SigningKey>>,
+}
+
+impl SigningKeyCache {
+    /// Create a new, empty, cache.
+    pub fn new() -> SigningKeyCache {
+        Default::default()
+    }
+
+    /// Get the signing key for the given scope, deriving it if it is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate chrono;
+    /// # extern crate warheadhateus;
+    /// #
+    /// # fn main() {
+    /// use chrono::UTC;
+    /// use warheadhateus::{AWSAuth, Region, Service, SigningKeyCache};
+    ///
+    /// let cache = SigningKeyCache::new();
+    /// let secret = "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY";
+    /// let now = UTC::now();
+    ///
+    /// match AWSAuth::new("https://s3.amazonaws.com/examplebucket/test.txt") {
+    ///     Ok(mut auth) => {
+    ///         auth.set_secret_access_key(secret);
+    ///         auth.set_date(now);
+    ///         if let Ok(key) = cache.get(secret, &now, Region::UsEast1, Service::S3) {
+    ///             auth.set_signing_key(key);
+    ///         }
+    ///     }
+    ///     Err(_) => {
+    ///         // Failure
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get(&self,
+               secret_access_key: &str,
+               date: &DateTime<UTC>,
+               region: Region,
+               service: Service)
+               -> Result<SigningKey, AWSAuthError> {
+        let hash = try!(secret_hash(secret_access_key));
+
+        {
+            let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+            if let Some(key) = keys.get(&(hash.clone(), region, service)) {
+                if key.matches_hash(&hash, date, region, service) {
+                    return Ok(key.clone());
+                }
+            }
+        }
+
+        let key = try!(SigningKey::new(secret_access_key, date, region, service));
+        let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+        keys.insert((hash, region, service), key.clone());
+        Ok(key)
+    }
+
+    /// Remove all of the cached keys.
+    pub fn clear(&self) {
+        self.keys.write().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// The hex encoded SHA256 hash of the secret access key.
+pub fn secret_hash(secret_access_key: &str) -> AWSAuthResult {
+    utils::hashed_data(Some(secret_access_key.as_bytes()))
+}
@@ -2,6 +2,40 @@ use error::{ParseRegionError, ParseServiceError};
 use std::fmt;
 use std::str::FromStr;
 
+/// Amazon S3 Flexible Checksum Algorithms (sent as a trailer in chunked mode)
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Checksum {
+    /// CRC32 (x-amz-checksum-crc32)
+    CRC32,
+    /// CRC32C (x-amz-checksum-crc32c)
+    CRC32C,
+    /// SHA-1 (x-amz-checksum-sha1)
+    SHA1,
+    /// SHA-256 (x-amz-checksum-sha256)
+    SHA256,
+}
+
+impl Checksum {
+    /// The name of the header carrying the checksum.  EG: x-amz-checksum-crc32
+    pub fn header_name(&self) -> &'static str {
+        match *self {
+            Checksum::CRC32 => "x-amz-checksum-crc32",
+            Checksum::CRC32C => "x-amz-checksum-crc32c",
+            Checksum::SHA1 => "x-amz-checksum-sha1",
+            Checksum::SHA256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// The length of the base64 encoded checksum.
+    pub fn encoded_len(&self) -> usize {
+        match *self {
+            Checksum::CRC32 | Checksum::CRC32C => 8,
+            Checksum::SHA1 => 28,
+            Checksum::SHA256 => 44,
+        }
+    }
+}
+
 /// Amazon S3 Mode of Operation
 pub enum Mode {
     /// Use this mode when transferring a payload in one chunk.
@@ -10,8 +44,23 @@ pub enum Mode {
     Chunked,
 }
 
+/// Amazon S3 Payload Signing Option
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum PayloadSigning {
+    /// Use this option to include the payload hash in the signature.
+    Signed,
+    /// Use this option to send the payload without signing it (`UNSIGNED-PAYLOAD`).
+    Unsigned,
+}
+
+impl Default for PayloadSigning {
+    fn default() -> PayloadSigning {
+        PayloadSigning::Signed
+    }
+}
+
 /// AWS Services
-#[derive(Debug,PartialEq)]
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
 pub enum Service {
     /// AWS DynamoDB (Managed NoSQL Database)
     DynamoDB,
@@ -25,6 +74,12 @@ pub enum Service {
     STS,
     /// AWS Lex (Conversational Interfaces)
     LEX,
+    /// AWS API Gateway (Execute API)
+    ExecuteAPI,
+    /// AWS OpenSearch Service (Elasticsearch)
+    ES,
+    /// Any other service, by its signing name (i.e. "kinesis")
+    Custom(&'static str),
 }
 
 impl fmt::Display for Service {
@@ -45,6 +100,8 @@ impl FromStr for Service {
             "s3" => Ok(Service::S3),
             "sts" => Ok(Service::STS),
             "lex" => Ok(Service::LEX),
+            "execute-api" => Ok(Service::ExecuteAPI),
+            "es" => Ok(Service::ES),
             _ => Err(ParseServiceError),
         }
     }
@@ -60,6 +117,9 @@ impl<'a> Into<String> for &'a Service {
             Service::S3 => "s3".to_owned(),
             Service::STS => "sts".to_owned(),
             Service::LEX => "lex".to_owned(),
+            Service::ExecuteAPI => "execute-api".to_owned(),
+            Service::ES => "es".to_owned(),
+            Service::Custom(name) => name.to_owned(),
         }
     }
 }
@@ -70,29 +130,112 @@ pub enum SigningVersion {
     Two,
     /// Version 4
     Four,
+    /// Amazon S3 Version 2 (the legacy `AWS AccessKeyId:Signature` scheme, only use this if the
+    /// S3 compatible service doesn't support 4)
+    S3Two,
+}
+
+/// Signature Version 2 Signing Method
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum SignatureMethod {
+    /// HmacSHA1
+    HmacSHA1,
+    /// HmacSHA256
+    HmacSHA256,
+}
+
+impl Default for SignatureMethod {
+    fn default() -> SignatureMethod {
+        SignatureMethod::HmacSHA256
+    }
+}
+
+impl fmt::Display for SignatureMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SignatureMethod::HmacSHA1 => write!(f, "HmacSHA1"),
+            SignatureMethod::HmacSHA256 => write!(f, "HmacSHA256"),
+        }
+    }
 }
 
 /// AWS Region
-#[derive(Debug,PartialEq)]
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
 pub enum Region {
     /// us-east-1
     UsEast1,
-    ///
+    /// us-west-1
     UsWest1,
-    ///
+    /// us-west-2
     UsWest2,
-    ///
+    /// eu-west-1
     EuWest1,
-    ///
+    /// eu-central-1
     EuCentral1,
-    ///
+    /// ap-southeast-1
     ApSoutheast1,
-    ///
+    /// ap-northeast-1
     ApNortheast1,
-    ///
+    /// ap-southeast-2
     ApSoutheast2,
-    ///
+    /// sa-east-1
     SaEast1,
+    /// us-east-2
+    UsEast2,
+    /// af-south-1
+    AfSouth1,
+    /// ap-east-1
+    ApEast1,
+    /// ap-east-2
+    ApEast2,
+    /// ap-northeast-2
+    ApNortheast2,
+    /// ap-northeast-3
+    ApNortheast3,
+    /// ap-south-1
+    ApSouth1,
+    /// ap-south-2
+    ApSouth2,
+    /// ap-southeast-3
+    ApSoutheast3,
+    /// ap-southeast-4
+    ApSoutheast4,
+    /// ap-southeast-5
+    ApSoutheast5,
+    /// ap-southeast-7
+    ApSoutheast7,
+    /// ca-central-1
+    CaCentral1,
+    /// ca-west-1
+    CaWest1,
+    /// eu-central-2
+    EuCentral2,
+    /// eu-north-1
+    EuNorth1,
+    /// eu-south-1
+    EuSouth1,
+    /// eu-south-2
+    EuSouth2,
+    /// eu-west-2
+    EuWest2,
+    /// eu-west-3
+    EuWest3,
+    /// il-central-1
+    IlCentral1,
+    /// me-central-1
+    MeCentral1,
+    /// me-south-1
+    MeSouth1,
+    /// mx-central-1
+    MxCentral1,
+    /// us-gov-east-1
+    UsGovEast1,
+    /// us-gov-west-1
+    UsGovWest1,
+    /// cn-north-1
+    CnNorth1,
+    /// cn-northwest-1
+    CnNorthwest1,
 }
 
 impl Default for Region {
@@ -122,6 +265,34 @@ impl FromStr for Region {
             "ap-northeast-1" => Ok(Region::ApNortheast1),
             "ap-southeast-2" => Ok(Region::ApSoutheast2),
             "sa-east-1" => Ok(Region::SaEast1),
+            "us-east-2" => Ok(Region::UsEast2),
+            "af-south-1" => Ok(Region::AfSouth1),
+            "ap-east-1" => Ok(Region::ApEast1),
+            "ap-east-2" => Ok(Region::ApEast2),
+            "ap-northeast-2" => Ok(Region::ApNortheast2),
+            "ap-northeast-3" => Ok(Region::ApNortheast3),
+            "ap-south-1" => Ok(Region::ApSouth1),
+            "ap-south-2" => Ok(Region::ApSouth2),
+            "ap-southeast-3" => Ok(Region::ApSoutheast3),
+            "ap-southeast-4" => Ok(Region::ApSoutheast4),
+            "ap-southeast-5" => Ok(Region::ApSoutheast5),
+            "ap-southeast-7" => Ok(Region::ApSoutheast7),
+            "ca-central-1" => Ok(Region::CaCentral1),
+            "ca-west-1" => Ok(Region::CaWest1),
+            "eu-central-2" => Ok(Region::EuCentral2),
+            "eu-north-1" => Ok(Region::EuNorth1),
+            "eu-south-1" => Ok(Region::EuSouth1),
+            "eu-south-2" => Ok(Region::EuSouth2),
+            "eu-west-2" => Ok(Region::EuWest2),
+            "eu-west-3" => Ok(Region::EuWest3),
+            "il-central-1" => Ok(Region::IlCentral1),
+            "me-central-1" => Ok(Region::MeCentral1),
+            "me-south-1" => Ok(Region::MeSouth1),
+            "mx-central-1" => Ok(Region::MxCentral1),
+            "us-gov-east-1" => Ok(Region::UsGovEast1),
+            "us-gov-west-1" => Ok(Region::UsGovWest1),
+            "cn-north-1" => Ok(Region::CnNorth1),
+            "cn-northwest-1" => Ok(Region::CnNorthwest1),
             _ => Err(ParseRegionError),
         }
     }
@@ -140,6 +311,53 @@ impl<'a> Into<String> for &'a Region {
             Region::ApNortheast1 => "ap-northeast-1".to_owned(),
             Region::ApSoutheast2 => "ap-southeast-2".to_owned(),
             Region::SaEast1 => "sa-east-1".to_owned(),
+            Region::UsEast2 => "us-east-2".to_owned(),
+            Region::AfSouth1 => "af-south-1".to_owned(),
+            Region::ApEast1 => "ap-east-1".to_owned(),
+            Region::ApEast2 => "ap-east-2".to_owned(),
+            Region::ApNortheast2 => "ap-northeast-2".to_owned(),
+            Region::ApNortheast3 => "ap-northeast-3".to_owned(),
+            Region::ApSouth1 => "ap-south-1".to_owned(),
+            Region::ApSouth2 => "ap-south-2".to_owned(),
+            Region::ApSoutheast3 => "ap-southeast-3".to_owned(),
+            Region::ApSoutheast4 => "ap-southeast-4".to_owned(),
+            Region::ApSoutheast5 => "ap-southeast-5".to_owned(),
+            Region::ApSoutheast7 => "ap-southeast-7".to_owned(),
+            Region::CaCentral1 => "ca-central-1".to_owned(),
+            Region::CaWest1 => "ca-west-1".to_owned(),
+            Region::EuCentral2 => "eu-central-2".to_owned(),
+            Region::EuNorth1 => "eu-north-1".to_owned(),
+            Region::EuSouth1 => "eu-south-1".to_owned(),
+            Region::EuSouth2 => "eu-south-2".to_owned(),
+            Region::EuWest2 => "eu-west-2".to_owned(),
+            Region::EuWest3 => "eu-west-3".to_owned(),
+            Region::IlCentral1 => "il-central-1".to_owned(),
+            Region::MeCentral1 => "me-central-1".to_owned(),
+            Region::MeSouth1 => "me-south-1".to_owned(),
+            Region::MxCentral1 => "mx-central-1".to_owned(),
+            Region::UsGovEast1 => "us-gov-east-1".to_owned(),
+            Region::UsGovWest1 => "us-gov-west-1".to_owned(),
+            Region::CnNorth1 => "cn-north-1".to_owned(),
+            Region::CnNorthwest1 => "cn-northwest-1".to_owned(),
         }
     }
 }
+
+/// The intermediate values of signing a request, along with the signature.
+///
+/// * For v2 signatures only the `string_to_sign` and `signature` are set.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct SigningOutput {
+    /// The canonical request.
+    pub canonical_request: String,
+    /// The hex encoded SHA256 hash of the canonical request.
+    pub canonical_request_hash: String,
+    /// The credential scope (i.e. `20130524/us-east-1/s3/aws4_request`).
+    pub credential_scope: String,
+    /// The names of the signed headers, lowercase and sorted.
+    pub signed_headers: Vec<String>,
+    /// The string to sign.
+    pub string_to_sign: String,
+    /// The signature.
+    pub signature: String,
+}
@@ -1,9 +1,12 @@
 use error::AWSAuthError;
+use hmac::{Hmac, Mac};
 use rustc_serialize::hex::ToHex;
+use sha1::Sha1;
 use sodium_sys::crypto::hash::sha2;
 use sodium_sys::crypto::utils;
 use sodium_sys::crypto::symmetrickey::hmacsha2;
 use sodium_sys::crypto::symmetrickey::hmacsha2::Family::SHA256;
+use urlparse::quote;
 
 /// Hash the given data (or an empty string) with SHA256.
 #[cfg_attr(feature = "clippy", allow(string_lit_as_bytes))]
@@ -31,3 +34,26 @@ pub fn signed_data(data: &[u8], key: &[u8]) -> Result<Vec<u8>, AWSAuthError> {
     let mac = try!(hmacsha2::finalize(&mut state, SHA256));
     Ok(mac.into())
 }
+
+/// Sign the given data with the given key (HMAC-SHA1).
+pub fn signed_data_sha1(data: &[u8], key: &[u8]) -> Result<Vec<u8>, AWSAuthError> {
+    let mut mac = try!(Hmac::<Sha1>::new_from_slice(key)
+        .map_err(|_| AWSAuthError::Other("invalid HMAC-SHA1 key")));
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// URI encode the given string, leaving only the unreserved characters (A-Z, a-z, 0-9, '-', '_',
+/// '.' and '~') as is.
+pub fn uri_encode(data: &str) -> Result<String, AWSAuthError> {
+    Ok(try!(quote(data, b"~")))
+}
+
+/// Compare the given byte slices in constant time (with respect to the contents).
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -1,2 +1,12 @@
 pub mod chunked;
+pub mod headers;
+pub mod multi_region;
+pub mod post_policy;
+pub mod presigned;
+pub mod query;
 pub mod single;
+#[cfg(feature = "futures-core")]
+pub mod stream;
+pub mod token;
+pub mod trailer;
+pub mod v2;
@@ -0,0 +1,49 @@
+use {ACCESS_KEY_ID, fail, HOST, SCOPE_DATE, SECRET_ACCESS_KEY, DATE_TIME_FMT};
+use chrono::UTC;
+use chrono::offset::TimeZone;
+use warheadhateus::{AWSAuth, hashed_data, HttpRequestMethod, Region, Service};
+
+const URL_1: &'static str = "https://examplebucket.s3.amazonaws.com/\
+                             ?prefix=photos%2F2013&prefix=a&list-type=2&marker=x=y&acl";
+const URL_2: &'static str = "https://examplebucket.s3.amazonaws.com/\
+                             ?key=a%20b&tilde=a%7Eb&plus=a%2Bb";
+const SIG_1: &'static str = "16c38aa90f14451381d94d2f3646a684ba9ebc33d5fc2ae02cbe23c5f42e1841";
+const SIG_2: &'static str = "31e32c1c64c2d43152bcf62dd06957ee175aa74971e0d3807bcb41b759c2762d";
+
+fn get_bucket(url: &str) -> String {
+    let mut auth = AWSAuth::new(url).unwrap_or_else(|e| fail(e));
+    let payload_hash = hashed_data(None).unwrap_or_else(|e| fail(e));
+    let scope_date = UTC.datetime_from_str(SCOPE_DATE, DATE_TIME_FMT).unwrap_or_else(|e| fail(e));
+    auth.set_request_type(HttpRequestMethod::GET);
+    auth.set_payload_hash(&payload_hash);
+    auth.set_date(scope_date);
+    auth.set_service(Service::S3);
+    auth.set_access_key_id(ACCESS_KEY_ID);
+    auth.set_secret_access_key(SECRET_ACCESS_KEY);
+    auth.set_region(Region::UsEast1);
+    auth.add_header("HOST", HOST);
+    auth.add_header("x-amz-content-sha256", &payload_hash);
+    auth.add_header("x-amz-date", SCOPE_DATE);
+
+    let ah = auth.auth_header().unwrap_or_else(|e| fail(e));
+    ah.rsplit("Signature=").next().unwrap_or("").to_owned()
+}
+
+#[test]
+fn test_repeated_keys_and_equals_in_value() {
+    assert!(get_bucket(URL_1) == SIG_1);
+}
+
+#[test]
+fn test_pre_encoded_values() {
+    assert!(get_bucket(URL_2) == SIG_2);
+    // Unencoded input canonicalizes the same way.
+    let url = "https://examplebucket.s3.amazonaws.com/?plus=a%2Bb&tilde=a~b&key=a b";
+    assert!(get_bucket(url) == SIG_2);
+}
+
+#[test]
+fn test_empty_value() {
+    let with_equals = get_bucket("https://examplebucket.s3.amazonaws.com/?acl=");
+    assert!(get_bucket("https://examplebucket.s3.amazonaws.com/?acl") == with_equals);
+}
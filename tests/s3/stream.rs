@@ -0,0 +1,64 @@
+use fail;
+use futures_core::Stream;
+use s3::chunked::{AWS_TEST_5, get_auth, get_body, SEED_SIG};
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use warheadhateus::ChunkedStream;
+
+/// A byte stream that is always ready, yielding the given pieces in order.
+struct Pieces(Vec<Vec<u8>>);
+
+impl Stream for Pieces {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<io::Result<Vec<u8>>>> {
+        if self.0.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Ready(Some(Ok(self.0.remove(0))))
+        }
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn collect(stream: &mut ChunkedStream<Pieces>) -> io::Result<Vec<u8>> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut body = Vec::new();
+    while let Poll::Ready(Some(chunk)) = Pin::new(&mut *stream).poll_next(&mut cx) {
+        body.extend(try!(chunk));
+    }
+    Ok(body)
+}
+
+#[test]
+fn test_chunked_stream() {
+    let auth = get_auth().unwrap_or_else(|e| fail(e));
+    let pieces = vec![97; 66560].chunks(1000).map(|c| c.to_vec()).collect();
+    let mut stream = ChunkedStream::new(auth, 65536, 66560, Pieces(pieces))
+        .unwrap_or_else(|e| fail(e));
+    assert!(stream.auth_header() == AWS_TEST_5);
+    assert!(stream.content_length() == 66824);
+    let body = collect(&mut stream).unwrap_or_else(|e| fail(e));
+
+    let mut auth = get_auth().unwrap_or_else(|e| fail(e));
+    assert!(body == get_body(&mut auth, SEED_SIG).unwrap_or_else(|e| fail(e)));
+}
+
+#[test]
+fn test_chunked_stream_short_payload() {
+    let auth = get_auth().unwrap_or_else(|e| fail(e));
+    let mut stream = ChunkedStream::new(auth, 65536, 66560, Pieces(vec![vec![97; 1000]]))
+        .unwrap_or_else(|e| fail(e));
+    match collect(&mut stream) {
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+        _ => panic!(),
+    }
+}
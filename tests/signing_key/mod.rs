@@ -0,0 +1,121 @@
+use {ACCESS_KEY_ID, DATE_TIME_FMT, fail, HOST, SCOPE_DATE, SECRET_ACCESS_KEY};
+use chrono::UTC;
+use chrono::offset::TimeZone;
+use std::sync::Arc;
+use std::thread;
+use warheadhateus::{AWSAuth, Region, Service, SigningKey, SigningKeyCache};
+
+const EMPTY_HASH: &'static str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+const STRING_TO_SIGN: &'static str = "AWS4-HMAC-SHA256\n20130524T000000Z\n\
+                                      20130524/us-east-1/s3/aws4_request\n\
+                                      7344ae5b7ee6c3e7e6b0fe0640412a37625d1fbfff95c48bbb2dc439649\
+                                      46972";
+const SIGNATURE: &'static str = "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb\
+                                 41";
+
+fn get_auth() -> AWSAuth {
+    let mut auth = AWSAuth::new("https://examplebucket.s3.amazonaws.com/test.txt")
+        .unwrap_or_else(|e| fail(e));
+    let scope_date = UTC.datetime_from_str(SCOPE_DATE, DATE_TIME_FMT).unwrap_or_else(|e| fail(e));
+    auth.set_date(scope_date);
+    auth.set_access_key_id(ACCESS_KEY_ID);
+    auth.set_secret_access_key(SECRET_ACCESS_KEY);
+    auth.add_header("HOST", HOST);
+    auth.add_header("x-amz-date", SCOPE_DATE);
+    auth.set_payload_hash(EMPTY_HASH);
+    auth.add_header("x-amz-content-sha256", EMPTY_HASH);
+    auth.add_header("Range", "bytes=0-9");
+    auth
+}
+
+#[test]
+fn test_signing_key() {
+    let date = UTC.datetime_from_str(SCOPE_DATE, DATE_TIME_FMT).unwrap_or_else(|e| fail(e));
+    let key = SigningKey::new(SECRET_ACCESS_KEY, &date, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    assert!(key.matches(SECRET_ACCESS_KEY, &date, Region::UsEast1, Service::S3));
+    assert!(!key.matches(SECRET_ACCESS_KEY, &date, Region::UsWest2, Service::S3));
+    assert!(!key.matches("AnotherSecretAccessKey", &date, Region::UsEast1, Service::S3));
+    assert!(key.sign(STRING_TO_SIGN).unwrap_or_else(|e| fail(e)) == SIGNATURE);
+}
+
+#[test]
+fn test_set_signing_key() {
+    let date = UTC.datetime_from_str(SCOPE_DATE, DATE_TIME_FMT).unwrap_or_else(|e| fail(e));
+    let mut auth = get_auth();
+    let key = SigningKey::new(SECRET_ACCESS_KEY, &date, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    auth.set_signing_key(key);
+    assert!(auth.signature().unwrap_or_else(|e| fail(e)) == SIGNATURE);
+
+    // A key for another scope is not used.
+    let mut auth = get_auth();
+    let key = SigningKey::new(SECRET_ACCESS_KEY, &date, Region::UsWest2, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    auth.set_signing_key(key);
+    assert!(auth.signature().unwrap_or_else(|e| fail(e)) == SIGNATURE);
+
+    // Nor is a key derived from another secret access key.
+    let mut auth = get_auth();
+    let key = SigningKey::new("AnotherSecretAccessKey", &date, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    auth.set_signing_key(key);
+    assert!(auth.signature().unwrap_or_else(|e| fail(e)) == SIGNATURE);
+
+    // Changing the secret access key after setting the key is checked too.
+    let mut auth = get_auth();
+    let key = SigningKey::new(SECRET_ACCESS_KEY, &date, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    auth.set_signing_key(key);
+    auth.set_secret_access_key("AnotherSecretAccessKey");
+    assert!(auth.signature().unwrap_or_else(|e| fail(e)) != SIGNATURE);
+    auth.set_secret_access_key(SECRET_ACCESS_KEY);
+    assert!(auth.signature().unwrap_or_else(|e| fail(e)) == SIGNATURE);
+}
+
+#[test]
+fn test_cache_rollover() {
+    let cache = SigningKeyCache::new();
+    let day_1 = UTC.datetime_from_str("20130524T235959Z", DATE_TIME_FMT)
+        .unwrap_or_else(|e| fail(e));
+    let day_2 = UTC.datetime_from_str("20130525T000000Z", DATE_TIME_FMT)
+        .unwrap_or_else(|e| fail(e));
+
+    let key = cache.get(SECRET_ACCESS_KEY, &day_1, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    assert!(key.sign(STRING_TO_SIGN).unwrap_or_else(|e| fail(e)) == SIGNATURE);
+    let key = cache.get(SECRET_ACCESS_KEY, &day_2, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    assert!(key.matches(SECRET_ACCESS_KEY, &day_2, Region::UsEast1, Service::S3));
+    assert!(!key.matches(SECRET_ACCESS_KEY, &day_1, Region::UsEast1, Service::S3));
+    assert!(key.sign(STRING_TO_SIGN).unwrap_or_else(|e| fail(e)) != SIGNATURE);
+
+    // Keys are cached per secret access key.
+    let key = cache.get("AnotherSecretAccessKey", &day_2, Region::UsEast1, Service::S3)
+        .unwrap_or_else(|e| fail(e));
+    assert!(key.matches("AnotherSecretAccessKey", &day_2, Region::UsEast1, Service::S3));
+    assert!(!key.matches(SECRET_ACCESS_KEY, &day_2, Region::UsEast1, Service::S3));
+}
+
+#[test]
+fn test_cache_threads() {
+    let cache = Arc::new(SigningKeyCache::new());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let cache = cache.clone();
+            thread::spawn(move || {
+                let date = UTC.datetime_from_str(SCOPE_DATE, DATE_TIME_FMT)
+                    .unwrap_or_else(|e| fail(e));
+                let mut auth = get_auth();
+                let key = cache.get(SECRET_ACCESS_KEY, &date, Region::UsEast1, Service::S3)
+                    .unwrap_or_else(|e| fail(e));
+                auth.set_signing_key(key);
+                auth.signature().unwrap_or_else(|e| fail(e))
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert!(handle.join().expect("Signing thread panicked!") == SIGNATURE);
+    }
+}
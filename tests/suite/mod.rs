@@ -0,0 +1,161 @@
+//! Runs the signer against the AWS SigV4 test suite (in `aws4_testsuite`).
+//!
+//! Each test case is a directory holding a raw request (`.req`), and the expected canonical
+//! request (`.creq`), string to sign (`.sts`), `Authorization` header (`.authz`) and signed
+//! request (`.sreq`).
+//!
+//! * The suite signs for the service "service", with paths that are encoded once.
+//! * The `post-sts-token` cases use a session token, which is only signed by the `-before` case.
+use {DATE_TIME_FMT, fail};
+use chrono::UTC;
+use chrono::offset::TimeZone;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use warheadhateus::{AWSAuth, hashed_data, HttpRequestMethod, Region, Service};
+
+const ACCESS_KEY_ID: &'static str = "AKIDEXAMPLE";
+const SECRET_ACCESS_KEY: &'static str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+const SECURITY_TOKEN: &'static str = "x-amz-security-token";
+
+struct Request {
+    method: HttpRequestMethod,
+    target: String,
+    head: Vec<String>,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+fn read(path: &Path) -> String {
+    let mut buf = String::new();
+    let mut file = File::open(path).unwrap_or_else(|e| fail(e));
+    file.read_to_string(&mut buf).unwrap_or_else(|e| fail(e));
+    buf
+}
+
+fn cases(dir: &Path, found: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap_or_else(|e| fail(e)) {
+        let path = entry.unwrap_or_else(|e| fail(e)).path();
+        if path.is_dir() {
+            cases(&path, found);
+        } else if path.extension().map_or(false, |x| x == "req") {
+            found.push(path.with_extension(""));
+        }
+    }
+}
+
+fn parse(req: &str) -> Request {
+    let (head, body) = match req.find("\n\n") {
+        Some(idx) => (&req[..idx], &req[idx + 2..]),
+        None => (req, ""),
+    };
+    let lines: Vec<&str> = head.lines().collect();
+    let first = lines[0];
+    let start = first.find(' ').expect("request line method");
+    let end = first.rfind(' ').expect("request line version");
+    let method = match &first[..start] {
+        "GET" => HttpRequestMethod::GET,
+        "POST" => HttpRequestMethod::POST,
+        m => panic!("unsupported method {}", m),
+    };
+
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in &lines[1..] {
+        if line.starts_with(' ') || line.starts_with('\t') {
+            // A folded line is another value of the previous header.
+            let name = headers.last().expect("folded header").0.clone();
+            headers.push((name, line.to_string()));
+        } else {
+            let colon = line.find(':').expect("header separator");
+            headers.push((line[..colon].to_owned(), line[colon + 1..].to_owned()));
+        }
+    }
+
+    Request {
+        method: method,
+        target: first[start + 1..end].to_owned(),
+        head: lines.iter().map(|x| x.to_string()).collect(),
+        headers: headers,
+        body: body.to_owned(),
+    }
+}
+
+fn check(case: &Path) {
+    let name = case.file_name().and_then(|x| x.to_str()).expect("case name").to_owned();
+    let expected = |ext: &str| read(&case.with_extension(ext));
+    let req = parse(&expected("req"));
+
+    let url = format!("https://example.amazonaws.com{}", req.target);
+    let mut auth = AWSAuth::new(&url).unwrap_or_else(|e| fail(e));
+    let payload_hash = hashed_data(Some(req.body.as_bytes())).unwrap_or_else(|e| fail(e));
+    auth.set_request_type(req.method);
+    auth.set_payload_hash(&payload_hash);
+    auth.set_service(Service::Custom("service"));
+    auth.set_region(Region::UsEast1);
+    auth.set_double_encode(false);
+    auth.set_access_key_id(ACCESS_KEY_ID);
+    auth.set_secret_access_key(SECRET_ACCESS_KEY);
+
+    let mut token = None;
+    for &(ref key, ref val) in &req.headers {
+        if key.to_lowercase() == "x-amz-date" {
+            let date = UTC.datetime_from_str(val, DATE_TIME_FMT).unwrap_or_else(|e| fail(e));
+            auth.set_date(date);
+        }
+        if key.to_lowercase() == SECURITY_TOKEN {
+            token = Some(val.clone());
+        }
+        auth.add_header(key, val);
+    }
+
+    // The `-after` case adds the token of the `-before` case once signed.
+    let after = name.ends_with("-after");
+    if after {
+        let before_name = name.replace("-after", "-before");
+        let suite_dir = case.parent().and_then(|x| x.parent()).expect("suite directory");
+        let before = suite_dir.join(&before_name).join(format!("{}.req", before_name));
+        let before = parse(&read(&before));
+        token = before.headers
+            .iter()
+            .find(|&&(ref k, _)| k.to_lowercase() == SECURITY_TOKEN)
+            .map(|&(_, ref v)| v.clone());
+        auth.set_sign_session_token(false);
+    }
+    if let Some(ref token) = token {
+        auth.set_session_token(token);
+    }
+
+    let creq = auth.canonical_request().unwrap_or_else(|e| fail(e));
+    assert!(creq == expected("creq"), "{}: canonical request\n{}", name, creq);
+    let sts = auth.string_to_sign().unwrap_or_else(|e| fail(e));
+    assert!(sts == expected("sts"), "{}: string to sign\n{}", name, sts);
+
+    // The suite separates the Authorization components with ", ".
+    let authz = auth.auth_header().unwrap_or_else(|e| fail(e));
+    assert!(authz == expected("authz").replace(", ", ","), "{}: authz\n{}", name, authz);
+
+    let mut sreq = req.head.clone();
+    sreq.push(format!("Authorization: {}", authz));
+    if let (true, Some(token)) = (after, token) {
+        sreq.push(format!("X-Amz-Security-Token:{}", token));
+    }
+    if !req.body.is_empty() {
+        sreq.push(String::new());
+        sreq.push(req.body.clone());
+    }
+    let sreq = sreq.join("\n");
+    assert!(sreq == expected("sreq").replace(", ", ","), "{}: signed request\n{}", name, sreq);
+}
+
+#[test]
+fn test_aws4_testsuite() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/suite/aws4_testsuite");
+    let mut found = Vec::new();
+    cases(&dir, &mut found);
+    found.sort();
+    assert!(found.len() == 29);
+
+    for case in &found {
+        check(case);
+    }
+}
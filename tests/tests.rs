@@ -1,8 +1,21 @@
 extern crate chrono;
+#[cfg(feature = "futures-core")]
+extern crate futures_core;
+#[cfg(feature = "http")]
+extern crate http;
+extern crate rustc_serialize;
 extern crate warheadhateus;
 
+mod credentials;
+#[cfg(feature = "http")]
+mod http_request;
 mod s3;
+mod signer;
+mod signing_key;
+mod suite;
+mod uri;
 mod v2;
+mod verify;
 
 use std::error::Error;
 use std::io::{self, Write};